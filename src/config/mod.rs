@@ -0,0 +1,9 @@
+//! Typed view over the Arma Reforger dedicated-server JSON config.
+//!
+//! The server accepts a superset of fields we don't all know about, so
+//! everything we don't explicitly model round-trips through `extra` instead
+//! of being dropped on save.
+
+mod schema;
+
+pub use schema::{GameConfig, ReforgerConfig};
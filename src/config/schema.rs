@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+fn default_bind_address() -> String {
+    "0.0.0.0".to_string()
+}
+
+fn default_bind_port() -> u16 {
+    2001
+}
+
+fn default_max_players() -> u32 {
+    32
+}
+
+/// `game` block of the server config: the scenario being hosted and its
+/// public-facing name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameConfig {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, rename = "scenarioId")]
+    pub scenario_id: String,
+    #[serde(default = "default_max_players", rename = "maxPlayers")]
+    pub max_players: u32,
+    #[serde(default, rename = "gameProperties")]
+    pub game_properties: Value,
+    #[serde(default)]
+    pub admins: Vec<String>,
+
+    /// Anything we don't model explicitly, preserved verbatim on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            scenario_id: String::new(),
+            max_players: default_max_players(),
+            game_properties: Value::Object(Map::new()),
+            admins: Vec::new(),
+            extra: Map::new(),
+        }
+    }
+}
+
+/// Typed view of a `configs/*.json` server config.
+///
+/// Deserializes the fields the launcher's editor cares about and keeps
+/// everything else in `extra` so round-tripping through the form never
+/// loses data the server relies on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReforgerConfig {
+    #[serde(default = "default_bind_address", rename = "bindAddress")]
+    pub bind_address: String,
+    #[serde(default = "default_bind_port", rename = "bindPort")]
+    pub bind_port: u16,
+    #[serde(default, rename = "publicAddress")]
+    pub public_address: String,
+    #[serde(default, rename = "publicPort")]
+    pub public_port: u16,
+    #[serde(default)]
+    pub game: GameConfig,
+
+    /// Anything we don't model explicitly, preserved verbatim on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Default for ReforgerConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: default_bind_address(),
+            bind_port: default_bind_port(),
+            public_address: String::new(),
+            public_port: 0,
+            game: GameConfig::default(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl ReforgerConfig {
+    pub fn from_json_str(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+
+    pub fn to_pretty_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Checks the fields the in-app editor exposes and returns a list of
+    /// human-readable problems, empty if the config is safe to save.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if self.bind_port == 0 {
+            errors.push("Bind port must be between 1 and 65535.".to_string());
+        }
+        if self.public_port != 0 && self.public_address.trim().is_empty() {
+            errors.push("Public port set without a public address.".to_string());
+        }
+        if self.game.scenario_id.trim().is_empty() {
+            errors.push("Scenario ID cannot be empty.".to_string());
+        }
+        if self.game.max_players == 0 || self.game.max_players > 256 {
+            errors.push("Max players must be between 1 and 256.".to_string());
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_config() -> ReforgerConfig {
+        let mut config = ReforgerConfig::default();
+        config.game.scenario_id = "{ECC61978EDCC2B5A}Missions/23_Campaign.conf".to_string();
+        config
+    }
+
+    #[test]
+    fn default_config_is_valid_once_scenario_id_is_set() {
+        assert!(valid_config().validate().is_empty());
+    }
+
+    #[test]
+    fn rejects_zero_bind_port() {
+        let mut config = valid_config();
+        config.bind_port = 0;
+        assert!(config
+            .validate()
+            .iter()
+            .any(|e| e.contains("Bind port")));
+    }
+
+    #[test]
+    fn rejects_public_port_without_public_address() {
+        let mut config = valid_config();
+        config.public_port = 2001;
+        config.public_address.clear();
+        assert!(config
+            .validate()
+            .iter()
+            .any(|e| e.contains("public address")));
+    }
+
+    #[test]
+    fn public_port_with_address_is_fine() {
+        let mut config = valid_config();
+        config.public_port = 2001;
+        config.public_address = "203.0.113.10".to_string();
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn rejects_empty_scenario_id() {
+        let mut config = valid_config();
+        config.game.scenario_id = "   ".to_string();
+        assert!(config
+            .validate()
+            .iter()
+            .any(|e| e.contains("Scenario ID")));
+    }
+
+    #[test]
+    fn rejects_max_players_out_of_range() {
+        let mut config = valid_config();
+        config.game.max_players = 0;
+        assert!(config
+            .validate()
+            .iter()
+            .any(|e| e.contains("Max players")));
+
+        let mut config = valid_config();
+        config.game.max_players = 257;
+        assert!(config
+            .validate()
+            .iter()
+            .any(|e| e.contains("Max players")));
+    }
+}
@@ -0,0 +1,86 @@
+//! Optional Discord Rich Presence integration, enabled via the
+//! `discord-rpc` feature and the "Discord Rich Presence" toggle in settings.
+//! With the feature disabled (or not compiled in) every call here is a
+//! no-op, so callers never need to branch on whether it's active.
+
+#[cfg(feature = "discord-rpc")]
+mod imp {
+    use discord_rich_presence::{activity, DiscordIpc, DiscordIpcClient};
+
+    // <<< Replace with your own Discord application ID if you want rich
+    // presence branded for your community's server. >>>
+    const DISCORD_APP_ID: &str = "0";
+
+    pub struct DiscordPresence {
+        client: Option<DiscordIpcClient>,
+    }
+
+    impl DiscordPresence {
+        pub fn new() -> Self {
+            Self { client: None }
+        }
+
+        /// Connects lazily and reconnects silently if Discord wasn't running
+        /// the last time we tried.
+        fn ensure_connected(&mut self) -> bool {
+            if self.client.is_some() {
+                return true;
+            }
+            let Ok(mut client) = DiscordIpcClient::new(DISCORD_APP_ID) else {
+                return false;
+            };
+            if client.connect().is_err() {
+                return false;
+            }
+            self.client = Some(client);
+            true
+        }
+
+        /// Shows the running config/scenario as the details line and the
+        /// port as the state, with `started_at` (unix seconds) as uptime.
+        pub fn set_playing(&mut self, details: &str, port: u16, started_at: i64) {
+            if !self.ensure_connected() {
+                return;
+            }
+            let state = format!("Port {port}");
+            let payload = activity::Activity::new()
+                .details(details)
+                .state(&state)
+                .timestamps(activity::Timestamps::new().start(started_at));
+            if self.client.as_mut().unwrap().set_activity(payload).is_err() {
+                // Discord likely closed since we connected; drop the client
+                // so the next call reconnects instead of failing forever.
+                self.client = None;
+            }
+        }
+
+        pub fn clear(&mut self) {
+            if let Some(client) = self.client.as_mut() {
+                let _ = client.clear_activity();
+            }
+        }
+    }
+
+    impl Drop for DiscordPresence {
+        fn drop(&mut self) {
+            self.clear();
+        }
+    }
+}
+
+#[cfg(not(feature = "discord-rpc"))]
+mod imp {
+    pub struct DiscordPresence;
+
+    impl DiscordPresence {
+        pub fn new() -> Self {
+            Self
+        }
+
+        pub fn set_playing(&mut self, _details: &str, _port: u16, _started_at: i64) {}
+
+        pub fn clear(&mut self) {}
+    }
+}
+
+pub use imp::DiscordPresence;
@@ -1,29 +1,227 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use std::{
+    collections::VecDeque,
     fs,
+    io::{BufRead, BufReader},
     path::PathBuf,
-    process::Command,
+    process::{Child, Command, Stdio},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
 };
 
 use directories::ProjectDirs;
 use eframe::egui;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use rfd::FileDialog;
 use serde::{Deserialize, Serialize};
 
+mod config;
+use config::ReforgerConfig;
+
+mod discord;
+use discord::DiscordPresence;
+
 const DEFAULT_CONFIGS_DIR: &str = "configs";
+#[cfg(windows)]
 const SERVER_EXE: &str = "ArmaReforgerServer.exe";
+#[cfg(not(windows))]
+const SERVER_EXE: &str = "ArmaReforgerServer";
+const LOG_RING_CAPACITY: usize = 500;
+const DEFAULT_INCLUDE_PATTERN: &str = "*.json";
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+const DEFAULT_PROFILE_NAME: &str = "Default";
+
+fn default_include_pattern() -> String {
+    DEFAULT_INCLUDE_PATTERN.to_string()
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// A named set of launch arguments, e.g. "Default" or "Low bandwidth".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ArgProfile {
+    name: String,
+    args: Vec<String>,
+}
+
+fn default_arg_profiles() -> Vec<ArgProfile> {
+    vec![ArgProfile {
+        name: DEFAULT_PROFILE_NAME.to_string(),
+        args: vec!["-maxFPS".to_string(), "120".to_string()],
+    }]
+}
 
-// <<< Edit your default args here >>>
-const FIXED_ARGS: &[&str] = &[
-    "-maxFPS", "120",
-    // add more fixed args here if needed
-];
+fn default_active_profile() -> String {
+    DEFAULT_PROFILE_NAME.to_string()
+}
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 struct AppSettings {
     last_config: Option<PathBuf>,
     server_dir: Option<PathBuf>, // if launcher is not next to the EXE
+    #[serde(default = "default_include_pattern")]
+    include_pattern: String,
+    #[serde(default)]
+    exclude_pattern: String,
+    #[serde(default = "default_arg_profiles")]
+    arg_profiles: Vec<ArgProfile>,
+    #[serde(default = "default_active_profile")]
+    active_profile: String,
+    /// Per-config profile override, keyed by the config's display path.
+    #[serde(default)]
+    config_profile_overrides: std::collections::BTreeMap<String, String>,
+    #[serde(default)]
+    discord_rpc_enabled: bool,
+    /// Off by default: a stray double-click in the config list launches a
+    /// server, which is risky to leave armed on a list an operator scrolls.
+    #[serde(default)]
+    double_click_to_launch: bool,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            last_config: None,
+            server_dir: None,
+            include_pattern: default_include_pattern(),
+            exclude_pattern: String::new(),
+            arg_profiles: default_arg_profiles(),
+            active_profile: default_active_profile(),
+            config_profile_overrides: std::collections::BTreeMap::new(),
+            discord_rpc_enabled: false,
+            double_click_to_launch: false,
+        }
+    }
+}
+
+/// An action gated behind a confirmation modal because it's destructive:
+/// starting a duplicate instance, killing a live process, or overwriting a
+/// config file on disk.
+#[derive(Debug, Clone)]
+enum ConfirmAction {
+    Launch(PathBuf),
+    /// Keyed by `RunningServer.id`, not a `Vec` index, since the index can
+    /// shift (another entry reaped, a restart re-pushing at the end) while
+    /// the modal is open and the background keeps polling.
+    Stop(u64),
+    Restart(u64),
+    SaveConfig,
+}
+
+impl AppSettings {
+    fn profile_named(&self, name: &str) -> Option<&ArgProfile> {
+        self.arg_profiles.iter().find(|p| p.name == name)
+    }
+
+    /// Resolves the arg profile to launch `config_path` with: its per-config
+    /// override if set, otherwise the globally active profile.
+    fn profile_for(&self, config_path: &PathBuf) -> &ArgProfile {
+        let key = config_path.display().to_string();
+        let name = self
+            .config_profile_overrides
+            .get(&key)
+            .unwrap_or(&self.active_profile);
+        self.profile_named(name)
+            .or_else(|| self.arg_profiles.first())
+            .expect("arg_profiles is never empty")
+    }
+}
+
+/// Builds a `GlobSet` from a comma-separated pattern list, ignoring blanks
+/// and any pattern that fails to compile.
+fn build_globset(patterns: &str) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pat in patterns.split(',').map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        if let Ok(glob) = Glob::new(pat) {
+            builder.add(glob);
+        }
+    }
+    builder.build().unwrap_or_else(|_| GlobSetBuilder::new().build().unwrap())
+}
+
+#[cfg(test)]
+mod build_globset_tests {
+    use super::*;
+
+    #[test]
+    fn empty_pattern_matches_nothing() {
+        let set = build_globset("");
+        assert!(!set.is_match("config.json"));
+    }
+
+    #[test]
+    fn matches_any_listed_pattern() {
+        let set = build_globset("*.json, *.conf");
+        assert!(set.is_match("server.json"));
+        assert!(set.is_match("mission.conf"));
+        assert!(!set.is_match("readme.txt"));
+    }
+
+    #[test]
+    fn ignores_blank_entries_and_invalid_globs() {
+        let set = build_globset(" , *.json, [, *.bak");
+        assert!(set.is_match("server.json"));
+        assert!(set.is_match("server.bak"));
+    }
+}
+
+/// Shared ring buffer of captured stdout/stderr lines for a running server.
+#[derive(Default)]
+struct LogBuffer {
+    lines: Mutex<VecDeque<String>>,
+}
+
+impl LogBuffer {
+    fn push(&self, line: String) {
+        let mut lines = self.lines.lock().unwrap();
+        if lines.len() >= LOG_RING_CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    fn snapshot(&self) -> Vec<String> {
+        self.lines.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+/// A server process the launcher spawned and is still tracking.
+struct RunningServer {
+    /// Stable identity, independent of this entry's position in `running`
+    /// (which shifts as other entries are stopped/restarted).
+    id: u64,
+    config_path: PathBuf,
+    child: Child,
+    started_at: Instant,
+    log: Arc<LogBuffer>,
+    exit_status: Option<String>,
+}
+
+impl RunningServer {
+    fn is_running(&mut self) -> bool {
+        match self.child.try_wait() {
+            Ok(Some(status)) => {
+                if self.exit_status.is_none() {
+                    self.exit_status = Some(format!("{status}"));
+                }
+                false
+            }
+            Ok(None) => true,
+            Err(_) => false,
+        }
+    }
+
+    fn uptime_secs(&self) -> u64 {
+        self.started_at.elapsed().as_secs()
+    }
 }
 
 struct LauncherApp {
@@ -33,6 +231,21 @@ struct LauncherApp {
     filter: String,
     selected_idx: Option<usize>,
     status: String,
+    running: Vec<RunningServer>,
+    next_running_id: u64,
+    viewing_log_id: Option<u64>,
+    editing_config: Option<ReforgerConfig>,
+    editing_config_path: Option<PathBuf>,
+    config_errors: Vec<String>,
+    config_game_properties_text: String,
+    ctx: egui::Context,
+    fs_watcher: Option<RecommendedWatcher>,
+    fs_events_rx: Option<mpsc::Receiver<()>>,
+    pending_refresh: Option<Instant>,
+    new_profile_name: String,
+    new_profile_args_text: String,
+    discord: DiscordPresence,
+    confirm: Option<ConfirmAction>,
 }
 
 impl LauncherApp {
@@ -76,7 +289,7 @@ impl LauncherApp {
         dir.join(SERVER_EXE)
     }
 
-    fn new() -> Self {
+    fn new(ctx: egui::Context) -> Self {
         let settings = Self::load_settings();
         let exe_dir = Self::exe_dir();
         let configs_dir = exe_dir.join(DEFAULT_CONFIGS_DIR);
@@ -87,8 +300,24 @@ impl LauncherApp {
             filter: String::new(),
             selected_idx: None,
             status: String::new(),
+            running: Vec::new(),
+            next_running_id: 0,
+            viewing_log_id: None,
+            editing_config: None,
+            editing_config_path: None,
+            config_errors: Vec::new(),
+            config_game_properties_text: String::new(),
+            ctx,
+            fs_watcher: None,
+            fs_events_rx: None,
+            pending_refresh: None,
+            new_profile_name: String::new(),
+            new_profile_args_text: String::new(),
+            discord: DiscordPresence::new(),
+            confirm: None,
         };
         app.refresh_configs();
+        app.recreate_watcher();
         // try auto-select last used if present
         if let Some(last) = &app.settings.last_config {
             if let Some(idx) = app
@@ -97,19 +326,139 @@ impl LauncherApp {
                 .position(|p| p.as_path() == last.as_path())
             {
                 app.selected_idx = Some(idx);
+                app.load_config_for_editing(idx);
             }
         }
         app
     }
 
+    /// (Re)starts the filesystem watcher on `configs_dir`. Called on
+    /// startup and whenever the user points the launcher at a new folder.
+    fn recreate_watcher(&mut self) {
+        self.fs_watcher = None;
+        self.fs_events_rx = None;
+
+        let (tx, rx) = mpsc::channel();
+        let ctx = self.ctx.clone();
+        let handler = move |res: notify::Result<Event>| {
+            if let Ok(event) = res {
+                if matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) {
+                    let _ = tx.send(());
+                    ctx.request_repaint();
+                }
+            }
+        };
+
+        match notify::recommended_watcher(handler) {
+            Ok(mut watcher) => {
+                if let Err(e) = watcher.watch(&self.configs_dir, RecursiveMode::NonRecursive) {
+                    self.status = format!("Failed to watch {}: {e}", self.configs_dir.display());
+                }
+                self.fs_watcher = Some(watcher);
+                self.fs_events_rx = Some(rx);
+            }
+            Err(e) => self.status = format!("Failed to start config watcher: {e}"),
+        }
+    }
+
+    /// Drains pending filesystem events and, once they've settled for
+    /// `WATCH_DEBOUNCE`, refreshes the config list.
+    fn poll_watcher(&mut self) {
+        let mut saw_event = false;
+        if let Some(rx) = &self.fs_events_rx {
+            while rx.try_recv().is_ok() {
+                saw_event = true;
+            }
+        }
+        if saw_event {
+            self.pending_refresh = Some(Instant::now());
+        }
+        if let Some(t) = self.pending_refresh {
+            if t.elapsed() >= WATCH_DEBOUNCE {
+                self.refresh_configs();
+                self.pending_refresh = None;
+            }
+        }
+    }
+
+    /// Loads `available_configs[idx]` into the editor form, replacing
+    /// whatever was being edited before.
+    fn load_config_for_editing(&mut self, idx: usize) {
+        self.config_errors.clear();
+        let Some(path) = self.available_configs.get(idx) else {
+            return;
+        };
+        match fs::read_to_string(path) {
+            Ok(txt) => match ReforgerConfig::from_json_str(&txt) {
+                Ok(cfg) => {
+                    self.config_game_properties_text = serde_json::to_string_pretty(
+                        &cfg.game.game_properties,
+                    )
+                    .unwrap_or_default();
+                    self.editing_config = Some(cfg);
+                    self.editing_config_path = Some(path.clone());
+                }
+                Err(e) => {
+                    self.editing_config = None;
+                    self.editing_config_path = None;
+                    self.status = format!("Failed to parse {}: {e}", path.display());
+                }
+            },
+            Err(e) => {
+                self.editing_config = None;
+                self.editing_config_path = None;
+                self.status = format!("Failed to read {}: {e}", path.display());
+            }
+        }
+    }
+
+    /// Validates the in-progress edit and, if it passes, writes it back to
+    /// disk. Unknown fields captured in `extra` round-trip untouched.
+    fn save_editing_config(&mut self) {
+        let (Some(cfg), Some(path)) = (&mut self.editing_config, &self.editing_config_path) else {
+            return;
+        };
+
+        match serde_json::from_str(&self.config_game_properties_text) {
+            Ok(v) => cfg.game.game_properties = v,
+            Err(e) => {
+                self.config_errors = vec![format!("Game properties is not valid JSON: {e}")];
+                return;
+            }
+        }
+
+        let errors = cfg.validate();
+        if !errors.is_empty() {
+            self.config_errors = errors;
+            return;
+        }
+
+        match cfg.to_pretty_json() {
+            Ok(json) => match fs::write(path, json) {
+                Ok(()) => {
+                    self.config_errors.clear();
+                    self.status = format!("Saved {}", path.display());
+                }
+                Err(e) => self.config_errors = vec![format!("Failed to write config: {e}")],
+            },
+            Err(e) => self.config_errors = vec![format!("Failed to serialize config: {e}")],
+        }
+    }
+
     fn refresh_configs(&mut self) {
         self.available_configs.clear();
         let dir = &self.configs_dir;
+        let include = build_globset(&self.settings.include_pattern);
+        let exclude = build_globset(&self.settings.exclude_pattern);
         if dir.exists() {
             if let Ok(rd) = fs::read_dir(dir) {
                 for e in rd.flatten() {
                     let p = e.path();
-                    if p.extension().map(|s| s.eq_ignore_ascii_case("json")).unwrap_or(false) {
+                    let Some(name) = p.file_name() else { continue };
+                    if include.is_match(name) && !exclude.is_match(name) {
                         self.available_configs.push(p);
                     }
                 }
@@ -118,7 +467,8 @@ impl LauncherApp {
         }
         if self.available_configs.is_empty() {
             self.status = format!(
-                "No .json configs found in {}",
+                "No configs matching '{}' found in {}",
+                self.settings.include_pattern,
                 self.configs_dir.display()
             );
         } else {
@@ -141,6 +491,130 @@ impl LauncherApp {
             .collect()
     }
 
+    /// Spawn `server_exe` with piped stdio and pump its stdout/stderr into `log`
+    /// from worker threads so the UI thread never blocks on the child.
+    #[cfg(windows)]
+    fn spawn_supervised(
+        server_exe: &PathBuf,
+        args: &[String],
+        log: Arc<LogBuffer>,
+    ) -> std::io::Result<Child> {
+        let mut cmd = Command::new(server_exe);
+        cmd.current_dir(server_exe.parent().unwrap())
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = cmd.spawn()?;
+
+        if let Some(stdout) = child.stdout.take() {
+            let log = log.clone();
+            thread::spawn(move || {
+                for line in BufReader::new(stdout).lines().flatten() {
+                    log.push(line);
+                }
+            });
+        }
+        if let Some(stderr) = child.stderr.take() {
+            let log = log.clone();
+            thread::spawn(move || {
+                for line in BufReader::new(stderr).lines().flatten() {
+                    log.push(format!("[stderr] {line}"));
+                }
+            });
+        }
+
+        Ok(child)
+    }
+
+    /// Same contract as the Windows version, but the child gets its own
+    /// process group (`setpgid`, not a new session as `setsid` would give),
+    /// so signals sent to the launcher's group, such as Ctrl+C in its
+    /// terminal, don't reach the server. Its stdout/stderr stay piped to
+    /// the launcher.
+    #[cfg(unix)]
+    fn spawn_supervised(
+        server_exe: &PathBuf,
+        args: &[String],
+        log: Arc<LogBuffer>,
+    ) -> std::io::Result<Child> {
+        use std::os::unix::process::CommandExt;
+
+        let mut cmd = Command::new(server_exe);
+        cmd.current_dir(server_exe.parent().unwrap())
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .process_group(0);
+
+        let mut child = cmd.spawn()?;
+
+        if let Some(stdout) = child.stdout.take() {
+            let log = log.clone();
+            thread::spawn(move || {
+                for line in BufReader::new(stdout).lines().flatten() {
+                    log.push(line);
+                }
+            });
+        }
+        if let Some(stderr) = child.stderr.take() {
+            let log = log.clone();
+            thread::spawn(move || {
+                for line in BufReader::new(stderr).lines().flatten() {
+                    log.push(format!("[stderr] {line}"));
+                }
+            });
+        }
+
+        Ok(child)
+    }
+
+    fn build_args(&self, config_path: &PathBuf) -> Vec<String> {
+        let mut args = self.settings.profile_for(config_path).args.clone();
+        args.push("-config".into());
+        args.push(config_path.display().to_string());
+        args
+    }
+
+    fn launch_config(&mut self, config_path: PathBuf) {
+        let server_exe = Self::server_exe_path(&self.settings);
+        if !server_exe.exists() {
+            self.status = format!(
+                "Server exe not found: {}\nSet the correct server directory.",
+                server_exe.display()
+            );
+            return;
+        }
+
+        let args = self.build_args(&config_path);
+
+        let log = Arc::new(LogBuffer::default());
+        match Self::spawn_supervised(&server_exe, &args, log.clone()) {
+            Ok(child) => {
+                self.status = format!("Launched:\n{} {}", server_exe.display(), args.join(" "));
+                self.settings.last_config = Some(config_path.clone());
+                self.save_settings();
+                if self.settings.discord_rpc_enabled {
+                    let (details, port) = Self::presence_details(&config_path);
+                    self.discord.set_playing(&details, port, unix_now());
+                }
+                let id = self.next_running_id;
+                self.next_running_id += 1;
+                self.running.push(RunningServer {
+                    id,
+                    config_path,
+                    child,
+                    started_at: Instant::now(),
+                    log,
+                    exit_status: None,
+                });
+            }
+            Err(e) => self.status = format!("Failed to launch: {e}"),
+        }
+    }
+
     fn launch_selected(&mut self) {
         let idx = match self.selected_idx {
             Some(i) => i,
@@ -153,56 +627,183 @@ impl LauncherApp {
             self.status = "Invalid selection.".into();
             return;
         }
-        let config_path = &self.available_configs[idx];
+        let config_path = self.available_configs[idx].clone();
+        self.request_launch(config_path);
+    }
 
-        let server_exe = Self::server_exe_path(&self.settings);
-        if !server_exe.exists() {
-            self.status = format!(
-                "Server exe not found: {}\nSet the correct server directory.",
-                server_exe.display()
-            );
+    fn running_idx_for(&self, config_path: &PathBuf) -> Option<usize> {
+        self.running
+            .iter()
+            .position(|s| &s.config_path == config_path && s.exit_status.is_none())
+    }
+
+    /// Launches `config_path` directly, unless it's already running, in
+    /// which case it routes through the confirmation modal first.
+    fn request_launch(&mut self, config_path: PathBuf) {
+        if self.running_idx_for(&config_path).is_some() {
+            self.confirm = Some(ConfirmAction::Launch(config_path));
+        } else {
+            self.launch_config(config_path);
+        }
+    }
+
+    fn request_stop(&mut self, id: u64) {
+        self.confirm = Some(ConfirmAction::Stop(id));
+    }
+
+    fn request_restart(&mut self, id: u64) {
+        self.confirm = Some(ConfirmAction::Restart(id));
+    }
+
+    fn request_save_config(&mut self) {
+        self.confirm = Some(ConfirmAction::SaveConfig);
+    }
+
+    /// Renders the modal confirmation dialog for the pending `ConfirmAction`,
+    /// if any, and carries it out when the operator confirms.
+    fn show_confirm_modal(&mut self, ctx: &egui::Context) {
+        let Some(action) = self.confirm.clone() else {
             return;
+        };
+
+        let message = match &action {
+            ConfirmAction::Launch(config_path) => {
+                let port = Self::presence_details(config_path).1;
+                format!(
+                    "{} is already running on port {port}.\nLaunch another instance anyway?",
+                    config_path.display()
+                )
+            }
+            ConfirmAction::Stop(id) => match self.running.iter().find(|s| s.id == *id) {
+                Some(server) => format!(
+                    "Stop {}? This will terminate the running process.",
+                    server.config_path.display()
+                ),
+                None => "Stop the selected server?".to_string(),
+            },
+            ConfirmAction::Restart(id) => match self.running.iter().find(|s| s.id == *id) {
+                Some(server) => format!(
+                    "Restart {}? This will terminate and relaunch the process.",
+                    server.config_path.display()
+                ),
+                None => "Restart the selected server?".to_string(),
+            },
+            ConfirmAction::SaveConfig => match &self.editing_config_path {
+                Some(path) => format!("Overwrite {} with the edited config?", path.display()),
+                None => "Overwrite the config file?".to_string(),
+            },
+        };
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new("Confirm")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label(message);
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Yes, continue").clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+
+        if confirmed {
+            self.confirm = None;
+            match action {
+                ConfirmAction::Launch(config_path) => self.launch_config(config_path),
+                ConfirmAction::Stop(id) => self.stop_running_by_id(id),
+                ConfirmAction::Restart(id) => self.restart_running_by_id(id),
+                ConfirmAction::SaveConfig => self.save_editing_config(),
+            }
+        } else if cancelled {
+            self.confirm = None;
         }
+    }
 
-        // Build arguments: fixed + -config "<path>"
-        let mut args: Vec<String> = FIXED_ARGS.iter().map(|s| s.to_string()).collect();
-        args.push("-config".into());
-        args.push(config_path.display().to_string());
+    /// Builds the Discord Rich Presence "details" line and bind port for
+    /// `config_path`, falling back to the file name and port 0 if the
+    /// config can't be parsed.
+    fn presence_details(config_path: &PathBuf) -> (String, u16) {
+        let name = config_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Arma Reforger server")
+            .to_string();
+        match fs::read_to_string(config_path).ok().and_then(|txt| ReforgerConfig::from_json_str(&txt).ok()) {
+            Some(cfg) if !cfg.game.scenario_id.is_empty() => {
+                (format!("{name} — {}", cfg.game.scenario_id), cfg.bind_port)
+            }
+            Some(cfg) => (name, cfg.bind_port),
+            None => (name, 0),
+        }
+    }
 
-        // Launch detached so the server lives after closing the launcher.
-        #[cfg(windows)]
-        {
-            use std::process::Stdio;
-
-            let mut full_args: Vec<String> = Vec::new();
-            full_args.push(server_exe.display().to_string());
-            full_args.extend(args.iter().cloned());
-
-            let mut cmd = Command::new("cmd");
-            cmd.current_dir(server_exe.parent().unwrap())
-                .arg("/c")
-                .arg("start")
-                .arg("") // window title
-                .args(&full_args)
-                .stdin(Stdio::null())
-                .stdout(Stdio::null())
-                .stderr(Stdio::null());
-
-            match cmd.spawn() {
-                Ok(_) => {
-                    self.status = format!("Launched:\n{} {}", server_exe.display(), args.join(" "));
-                    self.settings.last_config = Some(config_path.clone());
-                    self.save_settings();
-                }
-                Err(e) => self.status = format!("Failed to launch: {e}"),
+    /// Stops the server with the given stable id, if it's still present.
+    /// The id may no longer be in `running` by the time this runs (it
+    /// could have been reaped while a confirmation was pending), in which
+    /// case this is a no-op rather than indexing into a shifted `Vec`.
+    fn stop_running_by_id(&mut self, id: u64) {
+        let Some(idx) = self.running.iter().position(|s| s.id == id) else {
+            return;
+        };
+        let server = &mut self.running[idx];
+        let _ = server.child.kill();
+        let _ = server.child.wait();
+        self.status = format!("Stopped {}", server.config_path.display());
+        if self.viewing_log_id == Some(id) {
+            self.viewing_log_id = None;
+        }
+        self.running.remove(idx);
+        if self.running.is_empty() {
+            self.discord.clear();
+        }
+    }
+
+    fn restart_running_by_id(&mut self, id: u64) {
+        let Some(server) = self.running.iter().find(|s| s.id == id) else {
+            return;
+        };
+        let config_path = server.config_path.clone();
+        self.stop_running_by_id(id);
+        self.launch_config(config_path);
+    }
+
+    /// Drop entries for processes that exited on their own (crash, normal
+    /// shutdown, Ctrl+C...) rather than via an explicit Stop. Call this
+    /// after the frame has rendered so a row gets to show "exited" at least
+    /// once before it's pruned; `is_running()` (called earlier in the
+    /// frame) is what actually records the exit status.
+    fn reap_exited(&mut self) {
+        if let Some(id) = self.viewing_log_id {
+            if self.running.iter().any(|s| s.id == id && s.exit_status.is_some()) {
+                self.viewing_log_id = None;
             }
         }
+        self.running.retain(|s| s.exit_status.is_none());
+        if self.running.is_empty() {
+            self.discord.clear();
+        }
     }
 }
 
 impl eframe::App for LauncherApp {
     fn update(&mut self, ctx: &egui::Context, _: &mut eframe::Frame) {
+        for server in &mut self.running {
+            server.is_running();
+        }
+        self.poll_watcher();
+
+        let interactive = self.confirm.is_none();
+
         egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add_enabled_ui(interactive, |ui| {
             ui.heading("Arma Reforger Server Launcher");
 
             ui.separator();
@@ -218,6 +819,7 @@ impl eframe::App for LauncherApp {
                     {
                         self.configs_dir = dir;
                         self.refresh_configs();
+                        self.recreate_watcher();
                         self.selected_idx = None;
                     }
                 }
@@ -227,6 +829,19 @@ impl eframe::App for LauncherApp {
                 }
             });
 
+            ui.horizontal(|ui| {
+                ui.label("Include:");
+                if ui.text_edit_singleline(&mut self.settings.include_pattern).changed() {
+                    self.refresh_configs();
+                    self.save_settings();
+                }
+                ui.label("Exclude:");
+                if ui.text_edit_singleline(&mut self.settings.exclude_pattern).changed() {
+                    self.refresh_configs();
+                    self.save_settings();
+                }
+            });
+
             ui.horizontal(|ui| {
                 ui.label("Filter:");
                 ui.text_edit_singleline(&mut self.filter);
@@ -244,6 +859,7 @@ impl eframe::App for LauncherApp {
             egui::ScrollArea::vertical()
                 .auto_shrink([false; 2])
                 .max_height(260.0)
+                .id_salt("configs_scroll")
                 .show(ui, |ui| {
                     for (_row, &idx) in filtered.iter().enumerate() {
                         let name = self.available_configs[idx]
@@ -254,8 +870,9 @@ impl eframe::App for LauncherApp {
                         let resp = ui.selectable_label(selected, name);
                         if resp.clicked() {
                             self.selected_idx = Some(idx);
+                            self.load_config_for_editing(idx);
                         }
-                        if resp.double_clicked() {
+                        if resp.double_clicked() && self.settings.double_click_to_launch {
                             self.selected_idx = Some(idx);
                             self.launch_selected();
                         }
@@ -279,6 +896,9 @@ impl eframe::App for LauncherApp {
                             .available_configs
                             .iter()
                             .position(|p| p == &file);
+                        if let Some(idx) = self.selected_idx {
+                            self.load_config_for_editing(idx);
+                        }
                     }
                 }
 
@@ -295,6 +915,23 @@ impl eframe::App for LauncherApp {
                 if ui.button("Launch").clicked() {
                     self.launch_selected();
                 }
+
+                if ui
+                    .checkbox(&mut self.settings.discord_rpc_enabled, "Discord Rich Presence")
+                    .changed()
+                {
+                    if !self.settings.discord_rpc_enabled {
+                        self.discord.clear();
+                    }
+                    self.save_settings();
+                }
+
+                if ui
+                    .checkbox(&mut self.settings.double_click_to_launch, "Double-click to launch")
+                    .changed()
+                {
+                    self.save_settings();
+                }
             });
 
             ui.add_space(8.0);
@@ -309,10 +946,11 @@ impl eframe::App for LauncherApp {
             // Show full command preview
             if let Some(idx) = self.selected_idx {
                 if idx < self.available_configs.len() {
-                    let cfg = &self.available_configs[idx];
+                    let cfg = self.available_configs[idx].clone();
                     let exe = Self::server_exe_path(&self.settings);
+                    let profile = self.settings.profile_for(&cfg);
                     let mut preview = format!("{}", exe.display());
-                    for a in FIXED_ARGS {
+                    for a in &profile.args {
                         preview.push(' ');
                         preview.push_str(a);
                     }
@@ -323,7 +961,264 @@ impl eframe::App for LauncherApp {
                     ui.monospace(preview);
                 }
             }
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.heading("Config editor");
+
+            if let Some(cfg) = &mut self.editing_config {
+                egui::Grid::new("config_editor_grid")
+                    .num_columns(2)
+                    .spacing([8.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("Bind address:");
+                        ui.text_edit_singleline(&mut cfg.bind_address);
+                        ui.end_row();
+
+                        ui.label("Bind port:");
+                        ui.add(egui::DragValue::new(&mut cfg.bind_port).range(1..=65535));
+                        ui.end_row();
+
+                        ui.label("Public address:");
+                        ui.text_edit_singleline(&mut cfg.public_address);
+                        ui.end_row();
+
+                        ui.label("Public port:");
+                        ui.add(egui::DragValue::new(&mut cfg.public_port).range(0..=65535));
+                        ui.end_row();
+
+                        ui.label("Scenario ID:");
+                        ui.text_edit_singleline(&mut cfg.game.scenario_id);
+                        ui.end_row();
+
+                        ui.label("Server name:");
+                        ui.text_edit_singleline(&mut cfg.game.name);
+                        ui.end_row();
+
+                        ui.label("Max players:");
+                        ui.add(egui::DragValue::new(&mut cfg.game.max_players).range(1..=256));
+                        ui.end_row();
+                    });
+
+                ui.add_space(6.0);
+                ui.label("Admins (one SteamID64 per line):");
+                let mut admins_text = cfg.game.admins.join("\n");
+                if ui.text_edit_multiline(&mut admins_text).changed() {
+                    cfg.game.admins = admins_text
+                        .lines()
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect();
+                }
+
+                ui.add_space(6.0);
+                ui.label("Game properties (JSON):");
+                ui.text_edit_multiline(&mut self.config_game_properties_text);
+
+                ui.add_space(8.0);
+                if ui.button("Save config").clicked() {
+                    self.request_save_config();
+                }
+
+                for err in &self.config_errors {
+                    ui.colored_label(egui::Color32::RED, err);
+                }
+            } else {
+                ui.label("Select a config above to edit it.");
+            }
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.heading("Launch profiles");
+
+            let profile_names: Vec<String> = self
+                .settings
+                .arg_profiles
+                .iter()
+                .map(|p| p.name.clone())
+                .collect();
+
+            ui.horizontal(|ui| {
+                ui.label("Active profile:");
+                egui::ComboBox::new("active_profile_combo", "")
+                    .selected_text(self.settings.active_profile.clone())
+                    .show_ui(ui, |ui| {
+                        for name in &profile_names {
+                            if ui
+                                .selectable_value(&mut self.settings.active_profile, name.clone(), name)
+                                .changed()
+                            {
+                                self.save_settings();
+                            }
+                        }
+                    });
+            });
+
+            if let Some(idx) = self.selected_idx {
+                if let Some(cfg) = self.available_configs.get(idx).cloned() {
+                    let key = cfg.display().to_string();
+                    let mut override_name = self
+                        .settings
+                        .config_profile_overrides
+                        .get(&key)
+                        .cloned()
+                        .unwrap_or_else(|| "(use active profile)".to_string());
+                    let previous = override_name.clone();
+
+                    ui.horizontal(|ui| {
+                        ui.label("Profile for this config:");
+                        egui::ComboBox::new("config_profile_override_combo", "")
+                            .selected_text(override_name.clone())
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    &mut override_name,
+                                    "(use active profile)".to_string(),
+                                    "(use active profile)",
+                                );
+                                for name in &profile_names {
+                                    ui.selectable_value(&mut override_name, name.clone(), name);
+                                }
+                            });
+                    });
+
+                    if override_name != previous {
+                        if override_name == "(use active profile)" {
+                            self.settings.config_profile_overrides.remove(&key);
+                        } else {
+                            self.settings
+                                .config_profile_overrides
+                                .insert(key, override_name);
+                        }
+                        self.save_settings();
+                    }
+                }
+            }
+
+            ui.add_space(6.0);
+            let mut to_delete: Option<usize> = None;
+            for i in 0..self.settings.arg_profiles.len() {
+                let (name, args_joined) = {
+                    let p = &self.settings.arg_profiles[i];
+                    (p.name.clone(), p.args.join(" "))
+                };
+                ui.horizontal(|ui| {
+                    ui.label(format!("{name}:"));
+                    ui.monospace(args_joined);
+                    if self.settings.arg_profiles.len() > 1 && ui.button("Delete").clicked() {
+                        to_delete = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = to_delete {
+                let removed = self.settings.arg_profiles.remove(i);
+                if self.settings.active_profile == removed.name {
+                    self.settings.active_profile = self.settings.arg_profiles[0].name.clone();
+                }
+                self.save_settings();
+            }
+
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                ui.label("New profile name:");
+                ui.text_edit_singleline(&mut self.new_profile_name);
+            });
+            ui.label("Args (space-separated):");
+            ui.text_edit_singleline(&mut self.new_profile_args_text);
+            if ui.button("Add profile").clicked() {
+                let name = self.new_profile_name.trim().to_string();
+                if name.is_empty() {
+                    self.status = "Profile name cannot be empty.".into();
+                } else if self.settings.profile_named(&name).is_some() {
+                    self.status = format!("A profile named '{name}' already exists.");
+                } else {
+                    let args = self
+                        .new_profile_args_text
+                        .split_whitespace()
+                        .map(|s| s.to_string())
+                        .collect();
+                    self.settings.arg_profiles.push(ArgProfile { name, args });
+                    self.new_profile_name.clear();
+                    self.new_profile_args_text.clear();
+                    self.save_settings();
+                }
+            }
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.heading("Running servers");
+
+            if self.running.is_empty() {
+                ui.label("No servers running.");
+            } else {
+                let mut to_stop: Option<u64> = None;
+                let mut to_restart: Option<u64> = None;
+
+                for idx in 0..self.running.len() {
+                    let server = &self.running[idx];
+                    let name = server
+                        .config_path
+                        .file_name()
+                        .and_then(|s| s.to_str())
+                        .unwrap_or("<invalid>");
+                    let uptime = server.uptime_secs();
+                    let alive = server.exit_status.is_none();
+
+                    ui.horizontal(|ui| {
+                        let status_text = if alive {
+                            format!("{name} — up {}s", uptime)
+                        } else {
+                            format!(
+                                "{name} — exited ({})",
+                                server.exit_status.as_deref().unwrap_or("unknown")
+                            )
+                        };
+                        ui.label(status_text);
+
+                        if ui.button("View log").clicked() {
+                            self.viewing_log_id = Some(server.id);
+                        }
+                        if alive && ui.button("Restart").clicked() {
+                            to_restart = Some(server.id);
+                        }
+                        if ui.button("Stop").clicked() {
+                            to_stop = Some(server.id);
+                        }
+                    });
+                }
+
+                if let Some(id) = to_restart {
+                    self.request_restart(id);
+                }
+                if let Some(id) = to_stop {
+                    self.request_stop(id);
+                }
+            }
+
+            if let Some(id) = self.viewing_log_id {
+                if let Some(server) = self.running.iter().find(|s| s.id == id) {
+                    ui.add_space(8.0);
+                    ui.label("Live log:");
+                    let lines = server.log.snapshot();
+                    egui::ScrollArea::vertical()
+                        .auto_shrink([false; 2])
+                        .max_height(200.0)
+                        .id_salt("log_scroll")
+                        .stick_to_bottom(true)
+                        .show(ui, |ui| {
+                            ui.monospace(lines.join("\n"));
+                        });
+                } else {
+                    self.viewing_log_id = None;
+                }
+            }
+            });
         });
+
+        self.show_confirm_modal(ctx);
+        self.reap_exited();
+
+        // Keep polling child status and log output even while idle.
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
     }
 }
 
@@ -340,6 +1235,6 @@ fn main() -> eframe::Result<()> {
     eframe::run_native(
         "ArmA Reforger - Server Launcher",
         native_options,
-        Box::new(|_cc| Ok(Box::new(LauncherApp::new()))),
+        Box::new(|cc| Ok(Box::new(LauncherApp::new(cc.egui_ctx.clone())))),
     )
 }